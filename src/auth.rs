@@ -0,0 +1,71 @@
+use std::collections::HashMap;
+
+use warp::{Filter, Rejection};
+
+use crate::error::Error;
+
+/// Identity attached to a request once its bearer token has been validated.
+/// Only the subject is tracked for now, but it's enough to add per-user
+/// ownership checks later without touching the filter again.
+#[derive(Debug, Clone)]
+pub struct AuthContext {
+    pub subject: String,
+}
+
+/// A small in-memory map of bearer token to subject. Configured via the
+/// `AUTH_TOKENS` env var (`token:subject,token:subject`), falling back to a
+/// single demo token so the server still runs out of the box.
+#[derive(Debug, Clone)]
+pub struct TokenStore {
+    tokens: HashMap<String, String>,
+}
+
+impl TokenStore {
+    pub fn from_env() -> Self {
+        let tokens = match std::env::var("AUTH_TOKENS") {
+            Ok(raw) => raw
+                .split(',')
+                .filter_map(|pair| pair.split_once(':'))
+                .map(|(token, subject)| (token.to_string(), subject.to_string()))
+                .collect(),
+            Err(_) => {
+                let mut tokens = HashMap::new();
+                tokens.insert("demo-token".to_string(), "demo".to_string());
+                tokens
+            }
+        };
+
+        TokenStore { tokens }
+    }
+
+    fn subject_for(&self, token: &str) -> Option<&str> {
+        self.tokens.get(token).map(String::as_str)
+    }
+}
+
+/// Requires `Authorization: Bearer <token>` and injects an [`AuthContext`]
+/// for handlers that need the caller's identity.
+pub fn auth(
+    tokens: TokenStore,
+) -> impl Filter<Extract = (AuthContext,), Error = Rejection> + Clone {
+    warp::header::optional::<String>("authorization")
+        .and(warp::any().map(move || tokens.clone()))
+        .and_then(|header: Option<String>, tokens: TokenStore| async move {
+            // The `Bearer` auth scheme is case-insensitive (RFC 7235 §2.1),
+            // so `authorization: bearer <token>` must be accepted too.
+            let token = header
+                .as_deref()
+                .and_then(|value| {
+                    let (scheme, token) = value.split_once(' ')?;
+                    scheme.eq_ignore_ascii_case("Bearer").then_some(token)
+                })
+                .ok_or_else(|| warp::reject::custom(Error::Unauthorized))?;
+
+            match tokens.subject_for(token) {
+                Some(subject) => Ok(AuthContext {
+                    subject: subject.to_string(),
+                }),
+                None => Err(warp::reject::custom(Error::Unauthorized)),
+            }
+        })
+}
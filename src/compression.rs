@@ -0,0 +1,183 @@
+use std::io::Write;
+
+use flate2::write::GzEncoder;
+use flate2::Compression as GzCompression;
+use warp::http::header::CONTENT_ENCODING;
+use warp::http::HeaderValue;
+use warp::Reply;
+
+/// Bodies smaller than this aren't worth the CPU cost of compressing.
+const MIN_COMPRESSIBLE_BYTES: usize = 860;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionMode {
+    On,
+    Off,
+}
+
+impl CompressionMode {
+    /// Reads `--compression=<on|off>` from argv, falling back to the
+    /// `COMPRESSION` env var, falling back to on.
+    pub fn from_env_or_args() -> Self {
+        let from_args = std::env::args().find_map(|arg| arg.strip_prefix("--compression=").map(str::to_string));
+        let raw = from_args.or_else(|| std::env::var("COMPRESSION").ok());
+
+        match raw.as_deref() {
+            Some("off") => CompressionMode::Off,
+            _ => CompressionMode::On,
+        }
+    }
+}
+
+/// The response codings this service can produce. Kept separate from
+/// `CompressionMode`, which only toggles compression on/off; this is which
+/// coding wins once it's on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Encoding {
+    Gzip,
+    Brotli,
+}
+
+/// Parses an `Accept-Encoding` header and picks the coding this service
+/// should respond with, if any. Coding names are matched exactly (so
+/// `x-gzip` doesn't count) and a `q=0` weight is honored as an explicit
+/// refusal, per RFC 7231 §5.3.4. When both `gzip` and `br` are acceptable
+/// with equal preference, `br` wins ties since it compresses smaller for
+/// the same CPU budget.
+fn negotiate_encoding(accept_encoding: &str) -> Option<Encoding> {
+    let mut best: Option<(Encoding, f32)> = None;
+
+    for coding in accept_encoding.split(',') {
+        let mut parts = coding.split(';').map(str::trim);
+        let name = parts.next().unwrap_or("");
+        let encoding = if name.eq_ignore_ascii_case("br") {
+            Encoding::Brotli
+        } else if name.eq_ignore_ascii_case("gzip") {
+            Encoding::Gzip
+        } else {
+            continue;
+        };
+
+        let q: f32 = parts
+            .find_map(|param| param.strip_prefix("q="))
+            .and_then(|value| value.parse().ok())
+            .unwrap_or(1.0);
+        if q <= 0.0 {
+            continue;
+        }
+
+        let better = match best {
+            None => true,
+            Some((best_encoding, best_q)) => {
+                q > best_q || (q == best_q && best_encoding != Encoding::Brotli)
+            }
+        };
+        if better {
+            best = Some((encoding, q));
+        }
+    }
+
+    best.map(|(encoding, _)| encoding)
+}
+
+fn compress_gzip(body: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), GzCompression::default());
+    encoder.write_all(body)?;
+    encoder.finish()
+}
+
+fn compress_brotli(body: &[u8]) -> std::io::Result<Vec<u8>> {
+    let mut compressed = Vec::new();
+    {
+        let mut writer = brotli::CompressorWriter::new(&mut compressed, 4096, 5, 22);
+        writer.write_all(body)?;
+    }
+    Ok(compressed)
+}
+
+/// Compresses a JSON body with whichever coding `mode` and the client's
+/// `Accept-Encoding` agree on, when the body is large enough that
+/// compressing it is worth the CPU.
+pub fn compress_json(
+    mode: CompressionMode,
+    accept_encoding: Option<String>,
+    body: Vec<u8>,
+) -> impl Reply {
+    let encoding = accept_encoding.as_deref().and_then(negotiate_encoding);
+
+    let encoding = match encoding {
+        Some(encoding) if mode == CompressionMode::On && body.len() >= MIN_COMPRESSIBLE_BYTES => {
+            encoding
+        }
+        _ => {
+            return warp::reply::with_header(body, "Content-Type", "application/json")
+                .into_response()
+        }
+    };
+
+    let (compressed, content_encoding) = match encoding {
+        Encoding::Gzip => (compress_gzip(&body), "gzip"),
+        Encoding::Brotli => (compress_brotli(&body), "br"),
+    };
+
+    match compressed {
+        Ok(compressed) => {
+            let mut response =
+                warp::reply::with_header(compressed, "Content-Type", "application/json")
+                    .into_response();
+            response
+                .headers_mut()
+                .insert(CONTENT_ENCODING, HeaderValue::from_static(content_encoding));
+            response
+        }
+        Err(_) => warp::reply::with_header(body, "Content-Type", "application/json").into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn negotiate_encoding_picks_highest_q() {
+        assert_eq!(
+            negotiate_encoding("gzip;q=0.2, br;q=0.8"),
+            Some(Encoding::Brotli)
+        );
+        assert_eq!(
+            negotiate_encoding("gzip;q=0.8, br;q=0.2"),
+            Some(Encoding::Gzip)
+        );
+    }
+
+    #[test]
+    fn negotiate_encoding_prefers_brotli_on_tie() {
+        assert_eq!(negotiate_encoding("gzip, br"), Some(Encoding::Brotli));
+        assert_eq!(
+            negotiate_encoding("gzip;q=0.5, br;q=0.5"),
+            Some(Encoding::Brotli)
+        );
+    }
+
+    #[test]
+    fn negotiate_encoding_honors_explicit_refusal() {
+        assert_eq!(negotiate_encoding("gzip;q=0"), None);
+        assert_eq!(negotiate_encoding("br;q=0, gzip;q=0"), None);
+    }
+
+    #[test]
+    fn negotiate_encoding_matches_coding_names_exactly() {
+        assert_eq!(negotiate_encoding("x-gzip"), None);
+        assert_eq!(negotiate_encoding("gzip"), Some(Encoding::Gzip));
+    }
+
+    #[test]
+    fn negotiate_encoding_rejects_unsupported_codings() {
+        assert_eq!(negotiate_encoding("deflate, identity"), None);
+    }
+
+    #[test]
+    fn negotiate_encoding_empty_header_yields_none() {
+        assert_eq!(negotiate_encoding(""), None);
+    }
+}
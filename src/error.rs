@@ -0,0 +1,57 @@
+use warp::body::BodyDeserializeError;
+use warp::filters::cors::CorsForbidden;
+use warp::http::header::WWW_AUTHENTICATE;
+use warp::http::{HeaderValue, StatusCode};
+use warp::reject::Reject;
+use warp::{Rejection, Reply};
+
+#[derive(Debug)]
+pub enum Error {
+    ParseError(std::num::ParseIntError),
+    StorageError(String),
+    QuestionNotFound,
+    Unauthorized,
+    InvalidRange,
+}
+
+impl Reject for Error {}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match *self {
+            Error::ParseError(ref err) => write!(f, "Cannot parse parameter: {}", err),
+            Error::StorageError(ref reason) => write!(f, "Storage error: {}", reason),
+            Error::QuestionNotFound => write!(f, "Question not found"),
+            Error::Unauthorized => write!(f, "Missing or invalid bearer token"),
+            Error::InvalidRange => write!(f, "Pagination start must not be greater than end"),
+        }
+    }
+}
+
+pub async fn return_error(r: Rejection) -> Result<impl Reply, Rejection> {
+    let (body, status) = if let Some(error) = r.find::<Error>() {
+        let status = match error {
+            Error::ParseError(_) | Error::InvalidRange => StatusCode::RANGE_NOT_SATISFIABLE,
+            Error::StorageError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::QuestionNotFound => StatusCode::NOT_FOUND,
+            Error::Unauthorized => StatusCode::UNAUTHORIZED,
+        };
+
+        (error.to_string(), status)
+    } else if let Some(error) = r.find::<CorsForbidden>() {
+        (error.to_string(), StatusCode::FORBIDDEN)
+    } else if let Some(error) = r.find::<BodyDeserializeError>() {
+        (error.to_string(), StatusCode::BAD_REQUEST)
+    } else {
+        ("Route not found".to_string(), StatusCode::NOT_FOUND)
+    };
+
+    let mut response = warp::reply::with_status(body, status).into_response();
+    if status == StatusCode::UNAUTHORIZED {
+        response
+            .headers_mut()
+            .insert(WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+    }
+
+    Ok(response)
+}
@@ -1,186 +1,51 @@
-use std::collections::HashMap;
-use std::sync::Arc;
-use tokio::sync::RwLock;
-use serde::{Serialize, Deserialize};
-use warp::{
-    Filter,
-    Rejection, Reply,
-    http::StatusCode,
-    http::Method,
-    reject::Reject,
-    filters::{
-        cors::CorsForbidden,
-    },
-};
-
-#[derive(Clone)]
-struct Store {
-    questions: Arc<RwLock<HashMap<QuestionId, Question>>>,
-}
-
-impl Store {
-    fn new() -> Self {
-        Store {
-            questions: Arc::new(RwLock::new(Self::init())),
-        }
-    }
-
-    fn init() -> HashMap<QuestionId, Question> {
-        let file = include_str!("../questions.json");
-        serde_json::from_str(file).expect("can't read questions.json")
-    }
-}
-
-// Adding the Clone trait which we use in the
-// get_questions function further down
-#[derive(Debug, Serialize, Deserialize, Clone)]
-struct Question {
-    id: QuestionId,
-    title: String,
-    content: String,
-    tags: Option<Vec<String>>,
-}
-
-#[derive(Deserialize, Debug, Serialize, Clone, PartialEq, Eq, Hash)]
-struct QuestionId(String);
-
-#[derive(Debug)]
-enum Error {
-    ParseError(std::num::ParseIntError),
-    MissingParameters,
-}
-
-impl Reject for Error {}
-
-impl std::fmt::Display for Error {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match *self {
-            Error::ParseError(ref err) => write!(f, "Cannot parse parameter: {}", err),
-            Error::MissingParameters => write!(f, "Missing parameter"),
-        }
-    }
-}
-
-#[derive(Debug)]
-struct Pagination {
-    start: usize,
-    end: usize,
-}
-
-async fn get_questions(
-    params: HashMap<String, String>,
-    store: Store
-) -> Result<impl Reply, Rejection> {
-    if !params.is_empty() {
-        let pagination = extract_pagination(params)?;
-        let res: Vec<Question> = store
-            .questions
-            .read()
-            .await
-            .values()
-            .cloned()
-            .collect();
-        let res = &res[pagination.start..pagination.end];
-
-        Ok(warp::reply::json(&res))
-    } else {
-        let res: Vec<Question> = store
-            .questions
-            .read()
-            .await
-            .values()
-            .cloned()
-            .collect();
-        Ok(warp::reply::json(&res))
-    }
-}
-
-async fn add_question(
-    store: Store,
-    question: Question
-) -> Result<impl warp::Reply, warp::Rejection> {
-    store
-        .questions
-        .write()
+mod auth;
+mod compression;
+mod error;
+mod metrics;
+mod routes;
+mod store;
+mod types;
+
+#[cfg(feature = "postgres")]
+use store::PostgresStore;
+use store::{MemoryStore, Storage};
+
+use auth::TokenStore;
+use compression::CompressionMode;
+use metrics::QUESTIONS_TOTAL;
+use types::Pagination;
+
+async fn seed_questions_gauge<S: Storage>(store: &S) {
+    let total = store
+        .list(&Pagination::unbounded())
         .await
-        .insert(question.id.clone(), question);
-
-    Ok(warp::reply::with_status(
-        "Question added",
-        StatusCode::OK,
-    ))
-}
-
-async fn return_error(r: Rejection) -> Result<impl Reply, Rejection> {
-    if let Some(error) = r.find::<Error>() {
-        Ok(warp::reply::with_status(
-            error.to_string(),
-            StatusCode::RANGE_NOT_SATISFIABLE,
-        ))
-    } else if let Some(error) = r.find::<CorsForbidden>() {
-        Ok(warp::reply::with_status(
-            error.to_string(),
-            StatusCode::FORBIDDEN,
-        ))
-    } else {
-        Ok(warp::reply::with_status(
-            "Route not found".to_string(),
-            StatusCode::NOT_FOUND,
-        ))
-    }
-}
-
-fn extract_pagination(params: HashMap<String, String>) -> Result<Pagination, Error> {
-    if params.contains_key("start") && params.contains_key("end") {
-        return Ok(Pagination {
-            start: params
-                .get("start")
-                .unwrap()
-                .parse::<usize>()
-                .map_err(Error::ParseError)?,
-            end: params
-                .get("end")
-                .unwrap()
-                .parse::<usize>()
-                .map_err(Error::ParseError)?,
-        });
-    }
-
-    Err(Error::MissingParameters)
+        .expect("can't read initial questions")
+        .total;
+    QUESTIONS_TOTAL.set(total as i64);
 }
 
 #[tokio::main]
 async fn main() {
-    let store = Store::new();
-    let store_filter = warp::any().map(move || store.clone());
-
-    let cors = warp::cors()
-        .allow_any_origin()
-        .allow_header("content-type")
-        .allow_methods(
-            &[Method::PUT, Method::DELETE, Method::GET]
-        );
-
-    let get_questions = warp::get()
-        .and(warp::path("questions"))
-        .and(warp::path::end())
-        .and(warp::query())
-        .and(store_filter.clone())
-        .and_then(get_questions);
-
-    let add_question = warp::post()
-        .and(warp::path("questions"))
-        .and(warp::path::end())
-        .and(store_filter.clone())
-        .and(warp::body::json())
-        .and_then(add_question);
-
-    let routes = get_questions
-        .or(add_question)
-        .with(cors)
-        .recover(return_error);
-
-    warp::serve(routes)
-        .run(([127, 0, 0, 1], 3030))
-        .await;
+    let tokens = TokenStore::from_env();
+    let compression = CompressionMode::from_env_or_args();
+
+    #[cfg(feature = "postgres")]
+    let routes = {
+        let database_url = std::env::var("DATABASE_URL")
+            .expect("DATABASE_URL must be set when the postgres feature is enabled");
+        let store = PostgresStore::new(&database_url)
+            .await
+            .expect("can't connect to postgres");
+        seed_questions_gauge(&store).await;
+        routes::routes(store, tokens, compression)
+    };
+
+    #[cfg(not(feature = "postgres"))]
+    let routes = {
+        let store = MemoryStore::new();
+        seed_questions_gauge(&store).await;
+        routes::routes(store, tokens, compression)
+    };
+
+    warp::serve(routes).run(([127, 0, 0, 1], 3030)).await;
 }
@@ -0,0 +1,110 @@
+use once_cell::sync::Lazy;
+use prometheus::{
+    Encoder, Histogram, HistogramOpts, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use warp::log::Info;
+use warp::{Rejection, Reply};
+
+static REGISTRY: Lazy<Registry> = Lazy::new(Registry::new);
+
+static REQUESTS_TOTAL: Lazy<IntCounterVec> = Lazy::new(|| {
+    let counter = IntCounterVec::new(
+        Opts::new(
+            "questions_requests_total",
+            "Total HTTP requests, labeled by route and status class",
+        ),
+        &["route", "status"],
+    )
+    .expect("metric can be created");
+    REGISTRY
+        .register(Box::new(counter.clone()))
+        .expect("metric can be registered");
+    counter
+});
+
+static REQUEST_DURATION_SECONDS: Lazy<Histogram> = Lazy::new(|| {
+    let histogram = Histogram::with_opts(HistogramOpts::new(
+        "questions_request_duration_seconds",
+        "Handler latency in seconds",
+    ))
+    .expect("metric can be created");
+    REGISTRY
+        .register(Box::new(histogram.clone()))
+        .expect("metric can be registered");
+    histogram
+});
+
+/// Current number of stored questions. Updated by the `add_question` and
+/// `delete_question` handlers rather than by the `Storage` trait, so
+/// backends don't need to know metrics exist.
+pub static QUESTIONS_TOTAL: Lazy<IntGauge> = Lazy::new(|| {
+    let gauge = IntGauge::new("questions_total", "Current number of stored questions")
+        .expect("metric can be created");
+    REGISTRY
+        .register(Box::new(gauge.clone()))
+        .expect("metric can be registered");
+    gauge
+});
+
+/// Collapses a request path into the bounded set of route templates the
+/// service actually serves (e.g. `/questions/42` -> `/questions/{id}`), so
+/// the `route` label can't grow a new time series per id.
+fn route_template(path: &str) -> &'static str {
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match segments.as_slice() {
+        ["questions"] => "/questions",
+        ["questions", "search"] => "/questions/search",
+        ["questions", _] => "/questions/{id}",
+        ["metrics"] => "/metrics",
+        _ => "/other",
+    }
+}
+
+/// Records one finished request. Passed to `warp::log::custom` so it sees
+/// the final status code, after `return_error` has run.
+pub fn record(info: Info) {
+    let status = format!("{}xx", info.status().as_u16() / 100);
+    REQUESTS_TOTAL
+        .with_label_values(&[route_template(info.path()), &status])
+        .inc();
+    REQUEST_DURATION_SECONDS.observe(info.elapsed().as_secs_f64());
+}
+
+pub async fn metrics_handler() -> Result<impl Reply, Rejection> {
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    encoder
+        .encode(&REGISTRY.gather(), &mut buffer)
+        .expect("metrics can be encoded");
+
+    Ok(warp::reply::with_header(
+        buffer,
+        "Content-Type",
+        encoder.format_type(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn route_template_matches_known_routes() {
+        assert_eq!(route_template("/questions"), "/questions");
+        assert_eq!(route_template("/questions/search"), "/questions/search");
+        assert_eq!(route_template("/metrics"), "/metrics");
+    }
+
+    #[test]
+    fn route_template_collapses_ids_to_a_placeholder() {
+        assert_eq!(route_template("/questions/1"), "/questions/{id}");
+        assert_eq!(route_template("/questions/a1b2-c3d4"), "/questions/{id}");
+    }
+
+    #[test]
+    fn route_template_falls_back_for_unknown_paths() {
+        assert_eq!(route_template("/"), "/other");
+        assert_eq!(route_template("/questions/1/extra"), "/other");
+        assert_eq!(route_template("/unknown"), "/other");
+    }
+}
@@ -0,0 +1,85 @@
+pub mod question;
+
+use warp::http::Method;
+use warp::Filter;
+
+use crate::auth::{auth, TokenStore};
+use crate::compression::CompressionMode;
+use crate::error::return_error;
+use crate::metrics;
+use crate::store::Storage;
+
+pub fn routes<S: Storage + 'static>(
+    store: S,
+    tokens: TokenStore,
+    compression: CompressionMode,
+) -> impl Filter<Extract = impl warp::Reply> + Clone {
+    let store_filter = warp::any().map(move || store.clone());
+    let auth_filter = auth(tokens);
+    let compression_filter = warp::any().map(move || compression);
+    let accept_encoding = warp::header::optional::<String>("accept-encoding");
+
+    let cors = warp::cors()
+        .allow_any_origin()
+        .allow_header("content-type")
+        .allow_methods(&[Method::PUT, Method::DELETE, Method::GET]);
+
+    let get_questions = warp::get()
+        .and(warp::path("questions"))
+        .and(warp::path::end())
+        .and(warp::query())
+        .and(store_filter.clone())
+        .and(accept_encoding.clone())
+        .and(compression_filter.clone())
+        .and_then(question::get_questions);
+
+    let search_questions = warp::get()
+        .and(warp::path("questions"))
+        .and(warp::path("search"))
+        .and(warp::path::end())
+        .and(warp::query())
+        .and(store_filter.clone())
+        .and(accept_encoding)
+        .and(compression_filter)
+        .and_then(question::search_questions);
+
+    let add_question = warp::post()
+        .and(warp::path("questions"))
+        .and(warp::path::end())
+        .and(store_filter.clone())
+        .and(auth_filter.clone())
+        .and(warp::body::json())
+        .and_then(question::add_question);
+
+    let update_question = warp::put()
+        .and(warp::path("questions"))
+        .and(warp::path::param())
+        .and(warp::path::end())
+        .and(store_filter.clone())
+        .and(auth_filter.clone())
+        .and(warp::body::json())
+        .and_then(question::update_question);
+
+    let delete_question = warp::delete()
+        .and(warp::path("questions"))
+        .and(warp::path::param())
+        .and(warp::path::end())
+        .and(store_filter.clone())
+        .and(auth_filter)
+        .and_then(question::delete_question);
+
+    let metrics = warp::get()
+        .and(warp::path("metrics"))
+        .and(warp::path::end())
+        .and_then(metrics::metrics_handler);
+
+    search_questions
+        .or(get_questions)
+        .or(add_question)
+        .or(update_question)
+        .or(delete_question)
+        .or(metrics)
+        .with(cors)
+        .recover(return_error)
+        .with(warp::log::custom(metrics::record))
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use warp::http::StatusCode;
+use warp::{Rejection, Reply};
+
+use crate::auth::AuthContext;
+use crate::compression::{compress_json, CompressionMode};
+use crate::error::Error;
+use crate::metrics::QUESTIONS_TOTAL;
+use crate::store::Storage;
+use crate::types::pagination::extract_pagination;
+use crate::types::{Question, QuestionId};
+
+pub async fn get_questions<S: Storage>(
+    params: HashMap<String, String>,
+    store: S,
+    accept_encoding: Option<String>,
+    compression: CompressionMode,
+) -> Result<impl Reply, Rejection> {
+    let pagination = extract_pagination(params)?;
+    let page = store.list(&pagination).await?;
+
+    let body = serde_json::to_vec(&page).map_err(|e| Error::StorageError(e.to_string()))?;
+    Ok(compress_json(compression, accept_encoding, body))
+}
+
+/// Default number of results for `/questions/search` when `limit` isn't given.
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+pub async fn search_questions<S: Storage>(
+    params: HashMap<String, String>,
+    store: S,
+    accept_encoding: Option<String>,
+    compression: CompressionMode,
+) -> Result<impl Reply, Rejection> {
+    let query = params.get("q").map(String::as_str).unwrap_or("");
+    let limit = params
+        .get("limit")
+        .and_then(|value| value.parse::<usize>().ok())
+        .unwrap_or(DEFAULT_SEARCH_LIMIT);
+
+    let res = store.search(query, limit).await?;
+
+    let body = serde_json::to_vec(&res).map_err(|e| Error::StorageError(e.to_string()))?;
+    Ok(compress_json(compression, accept_encoding, body))
+}
+
+pub async fn add_question<S: Storage>(
+    store: S,
+    _auth: AuthContext,
+    question: Question,
+) -> Result<impl Reply, Rejection> {
+    if store.insert(question).await? {
+        QUESTIONS_TOTAL.inc();
+    }
+
+    Ok(warp::reply::with_status("Question added", StatusCode::OK))
+}
+
+pub async fn update_question<S: Storage>(
+    id: QuestionId,
+    store: S,
+    _auth: AuthContext,
+    question: Question,
+) -> Result<impl Reply, Rejection> {
+    if store.get(&id).await?.is_none() {
+        return Err(warp::reject::custom(Error::QuestionNotFound));
+    }
+
+    store.update(Question { id, ..question }).await?;
+
+    Ok(warp::reply::with_status("Question updated", StatusCode::OK))
+}
+
+pub async fn delete_question<S: Storage>(
+    id: QuestionId,
+    store: S,
+    _auth: AuthContext,
+) -> Result<impl Reply, Rejection> {
+    if store.get(&id).await?.is_none() {
+        return Err(warp::reject::custom(Error::QuestionNotFound));
+    }
+
+    store.delete(&id).await?;
+    QUESTIONS_TOTAL.dec();
+
+    Ok(warp::reply::with_status("Question deleted", StatusCode::OK))
+}
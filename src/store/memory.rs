@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use tokio::sync::RwLock;
+
+use crate::error::Error;
+use crate::types::{Page, Pagination, Question, QuestionId};
+
+use super::{tokenize, Storage};
+
+/// Term-frequency weight applied to a title token relative to a content
+/// token, so a match in the title outranks the same word buried in the body.
+const TITLE_WEIGHT: u32 = 3;
+/// Tags are short and deliberately chosen, so they count for more than a
+/// stray content word but less than a title hit.
+const TAG_WEIGHT: u32 = 2;
+
+type Index = HashMap<String, HashMap<QuestionId, u32>>;
+
+/// The original in-memory backend, seeded once from `questions.json` and
+/// lost on every restart. Kept around as the zero-dependency default and
+/// for tests. Also maintains an inverted index so `search` doesn't have to
+/// rescan every question on each request.
+#[derive(Clone)]
+pub struct MemoryStore {
+    questions: Arc<RwLock<HashMap<QuestionId, Question>>>,
+    index: Arc<RwLock<Index>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        let questions = Self::init();
+        let mut index = HashMap::new();
+        for question in questions.values() {
+            add_to_index(&mut index, question);
+        }
+
+        MemoryStore {
+            questions: Arc::new(RwLock::new(questions)),
+            index: Arc::new(RwLock::new(index)),
+        }
+    }
+
+    fn init() -> HashMap<QuestionId, Question> {
+        let file = include_str!("../../questions.json");
+        serde_json::from_str(file).expect("can't read questions.json")
+    }
+}
+
+impl Default for MemoryStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn question_token_weights(question: &Question) -> HashMap<String, u32> {
+    let mut weights = HashMap::new();
+
+    for token in tokenize(&question.title) {
+        *weights.entry(token).or_insert(0) += TITLE_WEIGHT;
+    }
+    for token in tokenize(&question.content) {
+        *weights.entry(token).or_insert(0) += 1;
+    }
+    for tag in question.tags.iter().flatten() {
+        for token in tokenize(tag) {
+            *weights.entry(token).or_insert(0) += TAG_WEIGHT;
+        }
+    }
+
+    weights
+}
+
+fn add_to_index(index: &mut Index, question: &Question) {
+    for (token, weight) in question_token_weights(question) {
+        *index
+            .entry(token)
+            .or_default()
+            .entry(question.id.clone())
+            .or_insert(0) += weight;
+    }
+}
+
+fn remove_from_index(index: &mut Index, question: &Question) {
+    for (token, weight) in question_token_weights(question) {
+        if let Some(postings) = index.get_mut(&token) {
+            if let Some(count) = postings.get_mut(&question.id) {
+                *count = count.saturating_sub(weight);
+                if *count == 0 {
+                    postings.remove(&question.id);
+                }
+            }
+            if postings.is_empty() {
+                index.remove(&token);
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for MemoryStore {
+    async fn list(&self, pagination: &Pagination) -> Result<Page, Error> {
+        let mut res: Vec<Question> = self.questions.read().await.values().cloned().collect();
+        // HashMap iteration order is nondeterministic, so sort before
+        // slicing to keep pages stable across requests.
+        res.sort_by(|a, b| a.id.0.cmp(&b.id.0));
+
+        let total = res.len();
+        let bounds = pagination.bounds(total);
+
+        Ok(Page {
+            questions: res[bounds].to_vec(),
+            total,
+        })
+    }
+
+    async fn get(&self, id: &QuestionId) -> Result<Option<Question>, Error> {
+        Ok(self.questions.read().await.get(id).cloned())
+    }
+
+    async fn insert(&self, question: Question) -> Result<bool, Error> {
+        let mut questions = self.questions.write().await;
+        let mut index = self.index.write().await;
+
+        let previous = questions.insert(question.id.clone(), question.clone());
+        if let Some(previous) = &previous {
+            remove_from_index(&mut index, previous);
+        }
+        add_to_index(&mut index, &question);
+
+        Ok(previous.is_none())
+    }
+
+    async fn update(&self, question: Question) -> Result<(), Error> {
+        self.insert(question).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &QuestionId) -> Result<(), Error> {
+        let mut questions = self.questions.write().await;
+        let mut index = self.index.write().await;
+
+        if let Some(removed) = questions.remove(id) {
+            remove_from_index(&mut index, &removed);
+        }
+
+        Ok(())
+    }
+
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<Question>, Error> {
+        let tokens = tokenize(query);
+        if tokens.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let index = self.index.read().await;
+        let mut scores: HashMap<QuestionId, u32> = HashMap::new();
+        let mut matches: HashMap<QuestionId, usize> = HashMap::new();
+        for token in &tokens {
+            if let Some(postings) = index.get(token) {
+                for (id, frequency) in postings {
+                    *scores.entry(id.clone()).or_insert(0) += frequency;
+                    *matches.entry(id.clone()).or_insert(0) += 1;
+                }
+            }
+        }
+
+        // Require every token to match, as `PostgresStore` does with its
+        // `AND`-joined `ILIKE` conditions (which match substrings there,
+        // whereas this index only matches whole tokens).
+        let mut scored: Vec<(QuestionId, u32)> = scores
+            .into_iter()
+            .filter(|(id, _)| matches.get(id).copied().unwrap_or(0) == tokens.len())
+            .collect();
+        scored.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0 .0.cmp(&b.0 .0)));
+
+        let questions = self.questions.read().await;
+        Ok(scored
+            .into_iter()
+            .take(limit)
+            .filter_map(|(id, _)| questions.get(&id).cloned())
+            .collect())
+    }
+}
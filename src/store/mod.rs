@@ -0,0 +1,79 @@
+pub mod memory;
+#[cfg(feature = "postgres")]
+pub mod postgres;
+
+pub use memory::MemoryStore;
+#[cfg(feature = "postgres")]
+pub use postgres::PostgresStore;
+
+use crate::error::Error;
+use crate::types::{Page, Pagination, Question, QuestionId};
+
+/// A storage backend for questions.
+///
+/// Handlers are written generically over `S: Storage` so the in-memory map
+/// used for the demo can be swapped for a real database without touching
+/// the route wiring in `routes::routes`.
+#[async_trait::async_trait]
+pub trait Storage: Clone + Send + Sync {
+    /// Returns the requested page together with the total number of
+    /// questions, so callers can build next/prev links without a second
+    /// round trip.
+    async fn list(&self, pagination: &Pagination) -> Result<Page, Error>;
+    async fn get(&self, id: &QuestionId) -> Result<Option<Question>, Error>;
+    /// Upserts `question`. Returns `true` if this created a new row and
+    /// `false` if it replaced an existing one, so callers (e.g. the
+    /// `questions_total` gauge) only count genuinely new questions.
+    async fn insert(&self, question: Question) -> Result<bool, Error>;
+    async fn update(&self, question: Question) -> Result<(), Error>;
+    async fn delete(&self, id: &QuestionId) -> Result<(), Error>;
+    /// Full-text search over title, content, and tags. Tokenizes `query`
+    /// with [`tokenize`], the same splitter questions are indexed with, and
+    /// requires every token to appear somewhere in the question — a
+    /// multi-word query narrows the result set rather than widening it.
+    /// Implementations are free to rank matches however they like.
+    /// `MemoryStore` matches whole tokens only; `PostgresStore` matches each
+    /// token as a case-insensitive substring, so for the same query it can
+    /// also return questions where a token only occurs inside a longer word
+    /// (`rust` matches `trusty`), which `MemoryStore` does not.
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<Question>, Error>;
+}
+
+/// Splits a field into lowercase alphanumeric tokens, the same way on
+/// indexing and on querying so the two always line up.
+pub fn tokenize(field: &str) -> Vec<String> {
+    field
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tokenize_lowercases_and_splits_on_punctuation() {
+        assert_eq!(
+            tokenize("Rust, async/await?"),
+            vec!["rust", "async", "await"]
+        );
+    }
+
+    #[test]
+    fn tokenize_collapses_runs_of_separators() {
+        assert_eq!(tokenize("a   b--c"), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn tokenize_empty_string_yields_no_tokens() {
+        assert!(tokenize("").is_empty());
+    }
+
+    #[test]
+    fn tokenize_keeps_alphanumeric_tokens_intact() {
+        assert_eq!(tokenize("warp0.3"), vec!["warp0", "3"]);
+    }
+}
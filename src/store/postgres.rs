@@ -0,0 +1,174 @@
+use sqlx::postgres::PgPoolOptions;
+use sqlx::{PgPool, Row};
+
+use crate::error::Error;
+use crate::types::{Page, Pagination, Question, QuestionId};
+
+use super::Storage;
+
+/// Postgres-backed storage. `tags` is stored as a native `text[]` column so
+/// it round-trips through sqlx without an extra join table.
+#[derive(Clone)]
+pub struct PostgresStore {
+    pool: PgPool,
+}
+
+impl PostgresStore {
+    pub async fn new(database_url: &str) -> Result<Self, Error> {
+        let pool = PgPoolOptions::new()
+            .max_connections(5)
+            .connect(database_url)
+            .await
+            .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        sqlx::query(
+            "CREATE TABLE IF NOT EXISTS questions (
+                id TEXT PRIMARY KEY,
+                title TEXT NOT NULL,
+                content TEXT NOT NULL,
+                tags TEXT[]
+            )",
+        )
+        .execute(&pool)
+        .await
+        .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        Ok(PostgresStore { pool })
+    }
+
+    /// Escapes `%`, `_`, and the escape character itself so a token is
+    /// matched literally by `ILIKE` rather than as a wildcard pattern.
+    fn escape_like(token: &str) -> String {
+        token
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
+    }
+
+    fn row_to_question(row: &sqlx::postgres::PgRow) -> Question {
+        Question {
+            id: QuestionId(row.get("id")),
+            title: row.get("title"),
+            content: row.get("content"),
+            tags: row.get::<Option<Vec<String>>, _>("tags"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl Storage for PostgresStore {
+    async fn list(&self, pagination: &Pagination) -> Result<Page, Error> {
+        let rows = sqlx::query("SELECT id, title, content, tags FROM questions ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        let total = rows.len();
+        let bounds = pagination.bounds(total);
+
+        Ok(Page {
+            questions: rows[bounds].iter().map(Self::row_to_question).collect(),
+            total,
+        })
+    }
+
+    async fn get(&self, id: &QuestionId) -> Result<Option<Question>, Error> {
+        let row = sqlx::query("SELECT id, title, content, tags FROM questions WHERE id = $1")
+            .bind(&id.0)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        Ok(row.as_ref().map(Self::row_to_question))
+    }
+
+    async fn insert(&self, question: Question) -> Result<bool, Error> {
+        // `xmax = 0` is only true for the row version created by this
+        // statement's own INSERT; `ON CONFLICT DO UPDATE` leaves it set to
+        // the updating transaction's id instead, so it doubles as a cheap
+        // "was this a new row?" flag without a separate SELECT.
+        let row = sqlx::query(
+            "INSERT INTO questions (id, title, content, tags) VALUES ($1, $2, $3, $4)
+             ON CONFLICT (id) DO UPDATE SET title = $2, content = $3, tags = $4
+             RETURNING (xmax = 0) AS inserted",
+        )
+        .bind(&question.id.0)
+        .bind(&question.title)
+        .bind(&question.content)
+        .bind(&question.tags)
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        Ok(row.get("inserted"))
+    }
+
+    async fn update(&self, question: Question) -> Result<(), Error> {
+        self.insert(question).await?;
+        Ok(())
+    }
+
+    async fn delete(&self, id: &QuestionId) -> Result<(), Error> {
+        sqlx::query("DELETE FROM questions WHERE id = $1")
+            .bind(&id.0)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        Ok(())
+    }
+
+    // No inverted index here: Postgres already has a query planner. Tokenize
+    // with `store::tokenize`, the same splitter `MemoryStore` indexes with,
+    // so a multi-word query requires every token to appear somewhere in the
+    // question rather than matching the raw string as one substring. Each
+    // token is matched with `ILIKE '%token%'`, i.e. as a substring of the
+    // title, content or a tag, not as a whole token. `tokenize` only yields
+    // alphanumeric tokens, so `escape_like` leaves them unchanged.
+    async fn search(&self, query: &str, limit: usize) -> Result<Vec<Question>, Error> {
+        let tokens = super::tokenize(query);
+        if tokens.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let patterns: Vec<String> = tokens
+            .iter()
+            .map(|token| format!("%{}%", Self::escape_like(token)))
+            .collect();
+
+        let mut sql = String::from("SELECT id, title, content, tags FROM questions WHERE ");
+        for (i, _) in patterns.iter().enumerate() {
+            if i > 0 {
+                sql.push_str(" AND ");
+            }
+            let p = i + 1;
+            sql.push_str(&format!(
+                "(title ILIKE ${p} OR content ILIKE ${p} OR EXISTS (
+                     SELECT 1 FROM unnest(tags) tag WHERE tag ILIKE ${p}
+                 ))"
+            ));
+        }
+        let title_hits = patterns
+            .iter()
+            .enumerate()
+            .map(|(i, _)| format!("(title ILIKE ${})::int", i + 1))
+            .collect::<Vec<_>>()
+            .join(" + ");
+        sql.push_str(&format!(
+            " ORDER BY ({title_hits}) DESC, id LIMIT ${}",
+            patterns.len() + 1
+        ));
+
+        let mut query_builder = sqlx::query(&sql);
+        for pattern in &patterns {
+            query_builder = query_builder.bind(pattern);
+        }
+        let rows = query_builder
+            .bind(limit as i64)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| Error::StorageError(e.to_string()))?;
+
+        Ok(rows.iter().map(Self::row_to_question).collect())
+    }
+}
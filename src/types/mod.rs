@@ -0,0 +1,5 @@
+pub mod pagination;
+pub mod question;
+
+pub use pagination::{Page, Pagination};
+pub use question::{Question, QuestionId};
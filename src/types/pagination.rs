@@ -0,0 +1,114 @@
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::error::Error;
+use crate::types::Question;
+
+/// A page of questions plus the total count, so clients can build
+/// next/prev links without a separate count request.
+#[derive(Debug, Serialize)]
+pub struct Page {
+    pub questions: Vec<Question>,
+    pub total: usize,
+}
+
+/// `start` defaults to `0` and `end` defaults to "the rest of the list" —
+/// both are validated and clamped by the caller rather than indexing
+/// blindly, since `HashMap` iteration order is nondeterministic and a
+/// naive slice panics on out-of-range input.
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination {
+    pub start: usize,
+    pub end: Option<usize>,
+}
+
+impl Pagination {
+    pub const fn unbounded() -> Self {
+        Pagination {
+            start: 0,
+            end: None,
+        }
+    }
+
+    /// Clamps this pagination window to `len`, returning the `start..end`
+    /// bounds to slice with. Never panics, regardless of how `self` was
+    /// constructed.
+    pub fn bounds(&self, len: usize) -> std::ops::Range<usize> {
+        let end = self.end.unwrap_or(len).min(len);
+        let start = self.start.min(end);
+
+        start..end
+    }
+}
+
+pub fn extract_pagination(params: HashMap<String, String>) -> Result<Pagination, Error> {
+    let start = match params.get("start") {
+        Some(value) => value.parse::<usize>().map_err(Error::ParseError)?,
+        None => 0,
+    };
+
+    let end = match params.get("end") {
+        Some(value) => Some(value.parse::<usize>().map_err(Error::ParseError)?),
+        None => None,
+    };
+
+    if let Some(end) = end {
+        if start > end {
+            return Err(Error::InvalidRange);
+        }
+    }
+
+    Ok(Pagination { start, end })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounds_defaults_to_the_whole_list() {
+        assert_eq!(Pagination::unbounded().bounds(5), 0..5);
+    }
+
+    #[test]
+    fn bounds_respects_start_and_end() {
+        let pagination = Pagination {
+            start: 1,
+            end: Some(3),
+        };
+        assert_eq!(pagination.bounds(5), 1..3);
+    }
+
+    #[test]
+    fn bounds_clamps_end_past_len() {
+        let pagination = Pagination {
+            start: 0,
+            end: Some(100),
+        };
+        assert_eq!(pagination.bounds(5), 0..5);
+    }
+
+    #[test]
+    fn bounds_clamps_start_past_len_to_an_empty_range() {
+        let pagination = Pagination {
+            start: 100,
+            end: None,
+        };
+        assert_eq!(pagination.bounds(5), 5..5);
+    }
+
+    #[test]
+    fn bounds_never_panics_when_start_exceeds_clamped_end() {
+        let pagination = Pagination {
+            start: 10,
+            end: Some(3),
+        };
+        assert_eq!(pagination.bounds(5), 3..3);
+    }
+
+    #[test]
+    fn bounds_on_an_empty_store_is_always_empty() {
+        assert_eq!(Pagination::unbounded().bounds(0), 0..0);
+    }
+}
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+// Clone is needed because the stores hand out copies of stored
+// questions (e.g. `MemoryStore::list` and `MemoryStore::get`).
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Question {
+    pub id: QuestionId,
+    pub title: String,
+    pub content: String,
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Deserialize, Debug, Serialize, Clone, PartialEq, Eq, Hash)]
+pub struct QuestionId(pub String);
+
+impl std::str::FromStr for QuestionId {
+    type Err = std::convert::Infallible;
+
+    fn from_str(id: &str) -> Result<Self, Self::Err> {
+        Ok(QuestionId(id.to_string()))
+    }
+}